@@ -77,6 +77,13 @@ async fn jwks() -> Json<Value> {
     pk.set_key_use("sig");
     kset.keys.push(pk);
 
+    let keypair = RsaKeyPair::from_pem(include_bytes!("../../../config/jwtRS256.key")).unwrap();
+    let mut pk = keypair.to_jwk_public_key();
+    pk.set_key_id("key-rsa-pss");
+    pk.set_algorithm("PS256");
+    pk.set_key_use("sig");
+    kset.keys.push(pk);
+
     Json(json!(kset))
 }
 
@@ -103,6 +110,9 @@ struct Claims {
     sub: &'static str,
     exp: usize,
     nbf: usize,
+    /// The audience(s) this token was issued for; may hold more than one value to exercise
+    /// any-of-these audience matching.
+    aud: Vec<&'static str>,
 }
 
 /// handler issuing test tokens (this is not a standard endpoint)
@@ -112,6 +122,11 @@ pub async fn tokens() -> Json<Value> {
         sub: "b@b.com",
         exp: 2000000000, // May 2033
         nbf: 1516239022, // Jan 2018
+        aud: vec!["aud1"],
+    };
+    let multi_aud_claims = Claims {
+        aud: vec!["aud1", "aud2"],
+        ..claims
     };
 
     let rsa_key = EncodingKey::from_rsa_pem(include_bytes!("../../../config/jwtRS256.key")).unwrap();
@@ -121,11 +136,15 @@ pub async fn tokens() -> Json<Value> {
     let rsa_token = encode(&build_header(Algorithm::RS256, "key-rsa"), &claims, &rsa_key).unwrap();
     let ec_token = encode(&build_header(Algorithm::ES256, "key-ec"), &claims, &ec_key).unwrap();
     let ed_token = encode(&build_header(Algorithm::EdDSA, "key-ed"), &claims, &ed_key).unwrap();
+    let ps_token = encode(&build_header(Algorithm::PS256, "key-rsa-pss"), &claims, &rsa_key).unwrap();
+    let multi_aud_token = encode(&build_header(Algorithm::RS256, "key-rsa"), &multi_aud_claims, &rsa_key).unwrap();
 
     Json(json!({
         "rsa": rsa_token,
         "ec": ec_token,
-        "ed": ed_token
+        "ed": ed_token,
+        "ps": ps_token,
+        "multi_aud": multi_aud_token
     }))
 }
 
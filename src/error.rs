@@ -0,0 +1,20 @@
+use thiserror::Error;
+
+/// Errors that can occur while authenticating a request.
+#[derive(Debug, Error)]
+pub enum AuthError {
+    #[error("invalid token: {0}")]
+    InvalidToken(#[from] jsonwebtoken::errors::Error),
+    #[error("no matching key found for kid {0:?}")]
+    NoMatchingKey(Option<String>),
+    #[error("unsupported key type: {0}")]
+    UnsupportedKeyType(String),
+    #[error("failed to fetch JWKS: {0}")]
+    JwksFetch(#[from] reqwest::Error),
+    #[error("token expired")]
+    Expired,
+    #[error("token not yet valid")]
+    NotYetValid,
+    #[error("token issued in the future")]
+    IssuedInTheFuture,
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::time::Instant;
+
+use crate::error::AuthError;
+use crate::jwks::{Jwk, JwkSet};
+
+/// Fallback refresh interval used when the JWKS response carries no `Cache-Control` or
+/// `Expires` header.
+const DEFAULT_FALLBACK_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// The minimum spacing between out-of-band refreshes triggered by an unknown `kid`, so a
+/// client hammering the authorizer with bogus key IDs cannot turn into a refresh storm
+/// against the JWKS endpoint.
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A self-refreshing cache of the keys published at a JWKS endpoint.
+///
+/// Keys are re-fetched in the background shortly before the TTL advertised by the
+/// provider's `Cache-Control: max-age` (or `Expires`) header runs out, so a request never
+/// pays the cost of the refresh on its own latency. If a token presents a `kid` this cache
+/// doesn't know about, an immediate refresh is triggered instead of waiting for the next
+/// scheduled one, bounded by [`MIN_REFRESH_INTERVAL`].
+pub struct JwkCache {
+    jwks_uri: String,
+    http: reqwest::Client,
+    fallback_ttl: Duration,
+    keys: RwLock<HashMap<String, Jwk>>,
+    last_refresh: AsyncMutex<Instant>,
+}
+
+impl JwkCache {
+    /// Creates a cache that fetches keys from `jwks_uri`, using `fallback_ttl` when the
+    /// response carries no caching headers.
+    pub fn new(jwks_uri: impl Into<String>, fallback_ttl: Duration) -> Arc<Self> {
+        Arc::new(Self {
+            jwks_uri: jwks_uri.into(),
+            http: reqwest::Client::new(),
+            fallback_ttl,
+            keys: RwLock::new(HashMap::new()),
+            last_refresh: AsyncMutex::new(Instant::now() - MIN_REFRESH_INTERVAL),
+        })
+    }
+
+    /// Creates a cache using the default fallback TTL.
+    pub fn with_default_ttl(jwks_uri: impl Into<String>) -> Arc<Self> {
+        Self::new(jwks_uri, DEFAULT_FALLBACK_TTL)
+    }
+
+    /// Spawns the background task that keeps this cache's keys fresh, refreshing again
+    /// just before each fetch's advertised TTL elapses.
+    pub fn spawn_background_refresh(self: &Arc<Self>) {
+        let cache = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                let ttl = cache.refresh().await.unwrap_or(cache.fallback_ttl);
+                // Wake a tenth of the TTL early so the keys are replaced before they go stale.
+                let ttl = ttl.saturating_sub(ttl / 10);
+                // A provider advertising a past `Expires` or `max-age=0` would otherwise
+                // make this loop busy-spin against the JWKS endpoint.
+                tokio::time::sleep(ttl.max(MIN_REFRESH_INTERVAL)).await;
+            }
+        });
+    }
+
+    /// Looks up a key by `kid`, triggering an out-of-band refresh (bounded by
+    /// [`MIN_REFRESH_INTERVAL`]) if it isn't present yet.
+    pub async fn get(&self, kid: &str) -> Option<Jwk> {
+        if let Some(key) = self.keys.read().unwrap().get(kid).cloned() {
+            return Some(key);
+        }
+
+        self.refresh_if_due().await;
+        self.keys.read().unwrap().get(kid).cloned()
+    }
+
+    async fn refresh_if_due(&self) {
+        let mut last_refresh = self.last_refresh.lock().await;
+        if last_refresh.elapsed() < MIN_REFRESH_INTERVAL {
+            return;
+        }
+        *last_refresh = Instant::now();
+        drop(last_refresh);
+        let _ = self.refresh().await;
+    }
+
+    /// Fetches the JWKS document, replaces the cached keys, and returns how long the
+    /// response says they remain fresh for.
+    async fn refresh(&self) -> Result<Duration, AuthError> {
+        let response = self.http.get(&self.jwks_uri).send().await?;
+
+        let ttl = cache_ttl(response.headers()).unwrap_or(self.fallback_ttl);
+
+        let jwk_set: JwkSet = response.json().await?;
+
+        {
+            // Scoped so this non-async-aware write guard is dropped before the `.await`
+            // below — held across an await point, it would make this future `!Send` and
+            // unusable with `tokio::spawn`.
+            let mut keys = self.keys.write().unwrap();
+            keys.clear();
+            for key in jwk_set.keys {
+                if let Some(kid) = key.kid.clone() {
+                    keys.insert(kid, key);
+                }
+            }
+        }
+
+        *self.last_refresh.lock().await = Instant::now();
+
+        Ok(ttl)
+    }
+}
+
+/// Reads the refresh interval a JWKS response advertises, preferring `Cache-Control:
+/// max-age` and falling back to `Expires`.
+fn cache_ttl(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    if let Some(max_age) = headers
+        .get(reqwest::header::CACHE_CONTROL)
+        .and_then(|v| v.to_str().ok())
+        .and_then(max_age_seconds)
+    {
+        return Some(Duration::from_secs(max_age));
+    }
+
+    headers
+        .get(reqwest::header::EXPIRES)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| httpdate::parse_http_date(v).ok())
+        .map(|expires| {
+            expires
+                .duration_since(std::time::SystemTime::now())
+                .unwrap_or(Duration::ZERO)
+        })
+}
+
+fn max_age_seconds(cache_control: &str) -> Option<u64> {
+    cache_control.split(',').find_map(|directive| {
+        let directive = directive.trim();
+        directive.strip_prefix("max-age=").and_then(|v| v.parse().ok())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::{HeaderMap, CACHE_CONTROL, EXPIRES};
+
+    use super::*;
+
+    #[test]
+    fn cache_ttl_prefers_max_age_over_expires() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CACHE_CONTROL, "public, max-age=120".parse().unwrap());
+        headers.insert(EXPIRES, httpdate::fmt_http_date(std::time::SystemTime::now() + Duration::from_secs(3600)).parse().unwrap());
+
+        assert_eq!(cache_ttl(&headers), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn cache_ttl_falls_back_to_expires() {
+        let mut headers = HeaderMap::new();
+        let expires_at = std::time::SystemTime::now() + Duration::from_secs(600);
+        headers.insert(EXPIRES, httpdate::fmt_http_date(expires_at).parse().unwrap());
+
+        let ttl = cache_ttl(&headers).expect("ttl parsed from Expires");
+        // Allow a little slack for the time spent formatting/parsing the header.
+        assert!(ttl.as_secs() > 590 && ttl.as_secs() <= 600);
+    }
+
+    #[test]
+    fn cache_ttl_past_expires_yields_zero_not_negative() {
+        let mut headers = HeaderMap::new();
+        let expires_at = std::time::SystemTime::now() - Duration::from_secs(60);
+        headers.insert(EXPIRES, httpdate::fmt_http_date(expires_at).parse().unwrap());
+
+        assert_eq!(cache_ttl(&headers), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn cache_ttl_none_without_caching_headers() {
+        assert_eq!(cache_ttl(&HeaderMap::new()), None);
+    }
+}
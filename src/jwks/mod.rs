@@ -0,0 +1,195 @@
+mod cache;
+
+pub use cache::JwkCache;
+
+use base64::engine::{general_purpose::STANDARD, Engine};
+use jsonwebtoken::{Algorithm, DecodingKey};
+use serde::Deserialize;
+use x509_parser::prelude::{FromDer, X509Certificate};
+use x509_parser::public_key::PublicKey as X509PublicKey;
+
+use crate::error::AuthError;
+
+/// A single JSON Web Key, as published on a JWKS endpoint.
+///
+/// Most real-world JWKS documents only carry the bare algebraic components of the key
+/// (`n`/`e` for RSA, `crv`/`x`/`y` for EC) rather than anything that round-trips through
+/// PEM, so [`Jwk::decoding_key`] builds a [`DecodingKey`] straight from those fields. When
+/// they're absent, [`Jwk::decoding_key`] falls back to the leaf certificate in `x5c` —
+/// standard-base64 DER X.509, per RFC 7517 — extracting its SubjectPublicKeyInfo rather than
+/// decoding the certificate bytes themselves as if they were a raw key.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Jwk {
+    pub kty: String,
+    pub kid: Option<String>,
+    pub alg: Option<String>,
+    #[serde(rename = "use")]
+    pub use_: Option<String>,
+    // RSA
+    pub n: Option<String>,
+    pub e: Option<String>,
+    // EC
+    pub crv: Option<String>,
+    pub x: Option<String>,
+    pub y: Option<String>,
+    pub x5c: Option<Vec<String>>,
+}
+
+impl Jwk {
+    /// Builds a [`DecodingKey`] from this JWK's algebraic components, falling back to the
+    /// leaf `x5c` certificate when they're absent.
+    ///
+    /// RSA keys are built from `n`/`e` with [`DecodingKey::from_rsa_components`]; EC keys
+    /// have their public point reconstructed from `x`/`y` on the named curve.
+    pub fn decoding_key(&self) -> Result<DecodingKey, AuthError> {
+        match self.kty.as_str() {
+            "RSA" => match (self.n.as_deref(), self.e.as_deref()) {
+                (Some(n), Some(e)) => Ok(DecodingKey::from_rsa_components(n, e)?),
+                _ => self.decoding_key_from_x5c(),
+            },
+            "EC" => match (self.crv.as_deref(), self.x.as_deref(), self.y.as_deref()) {
+                // `DecodingKey::from_ec_components` only supports the P-256 curve today.
+                (Some("P-256"), Some(x), Some(y)) => Ok(DecodingKey::from_ec_components(x, y)?),
+                (Some(other), Some(_), Some(_)) => Err(AuthError::UnsupportedKeyType(format!("unsupported EC curve {other}"))),
+                _ => self.decoding_key_from_x5c(),
+            },
+            kty => Err(AuthError::UnsupportedKeyType(kty.to_owned())),
+        }
+    }
+
+    /// Extracts a [`DecodingKey`] from the SubjectPublicKeyInfo of this JWK's leaf `x5c`
+    /// certificate.
+    fn decoding_key_from_x5c(&self) -> Result<DecodingKey, AuthError> {
+        let leaf = self
+            .x5c
+            .as_ref()
+            .and_then(|chain| chain.first())
+            .ok_or_else(|| missing("x5c"))?;
+
+        let der = STANDARD
+            .decode(leaf)
+            .map_err(|e| AuthError::UnsupportedKeyType(format!("x5c entry is not valid base64: {e}")))?;
+        let (_, cert) = X509Certificate::from_der(&der)
+            .map_err(|e| AuthError::UnsupportedKeyType(format!("x5c entry is not a valid X.509 certificate: {e}")))?;
+        let public_key = cert
+            .public_key()
+            .parsed()
+            .map_err(|e| AuthError::UnsupportedKeyType(format!("x5c certificate has an unsupported public key: {e}")))?;
+
+        match public_key {
+            X509PublicKey::RSA(rsa) => {
+                // DER INTEGER encodes a leading `0x00` whenever the modulus's high bit is
+                // set, to keep it from being read as negative; `ring` (which backs
+                // `jsonwebtoken`'s RSA verification) treats that byte as part of the
+                // modulus itself and rejects every signature unless it's stripped.
+                let modulus = match rsa.modulus {
+                    [0, rest @ ..] => rest,
+                    modulus => modulus,
+                };
+                Ok(DecodingKey::from_rsa_raw_components(modulus, rsa.exponent))
+            }
+            // x509-parser's EC point data is the same uncompressed `0x04 || x || y` encoding
+            // `DecodingKey::from_ec_components` builds internally, so it can be handed
+            // straight to `from_ec_der`.
+            X509PublicKey::EC(point) => Ok(DecodingKey::from_ec_der(point.data())),
+            other => Err(AuthError::UnsupportedKeyType(format!("unsupported x5c public key type (key size {} bits)", other.key_size()))),
+        }
+    }
+
+    /// The algorithms this key's `kty` (and, for EC, `crv`) can ever verify.
+    ///
+    /// `jsonwebtoken` rejects a [`jsonwebtoken::Validation`] outright if *any* of its
+    /// configured algorithms belong to a different key family than the [`DecodingKey`]
+    /// being verified against, so a [`crate::validation::Validation`] spanning RSA and EC
+    /// (the default) must be narrowed to the matched key's family before use.
+    pub(crate) fn compatible_algorithms(&self) -> &'static [Algorithm] {
+        match self.kty.as_str() {
+            "RSA" => &[
+                Algorithm::RS256,
+                Algorithm::RS384,
+                Algorithm::RS512,
+                Algorithm::PS256,
+                Algorithm::PS384,
+                Algorithm::PS512,
+            ],
+            "EC" if self.crv.as_deref() == Some("P-256") => &[Algorithm::ES256],
+            _ => &[],
+        }
+    }
+}
+
+fn missing(field: &str) -> AuthError {
+    AuthError::UnsupportedKeyType(format!("JWK is missing required field `{field}`"))
+}
+
+/// The document a JWKS endpoint serves: a flat list of keys.
+#[derive(Debug, Deserialize)]
+pub(crate) struct JwkSet {
+    pub keys: Vec<Jwk>,
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonwebtoken::{decode, encode, Algorithm, EncodingKey, Header, Validation};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    const RSA_TEST_KEY_PEM: &[u8] = include_bytes!("../../testdata/rsa_test_key.pem");
+    const RSA_TEST_CERT_B64: &str = include_str!("../../testdata/rsa_test_cert.b64");
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Claims {
+        sub: String,
+        exp: usize,
+    }
+
+    fn x5c_only_rsa_jwk() -> Jwk {
+        Jwk {
+            kty: "RSA".to_owned(),
+            kid: Some("x5c-rsa".to_owned()),
+            alg: Some("RS256".to_owned()),
+            use_: Some("sig".to_owned()),
+            n: None,
+            e: None,
+            crv: None,
+            x: None,
+            y: None,
+            x5c: Some(vec![RSA_TEST_CERT_B64.to_owned()]),
+        }
+    }
+
+    #[test]
+    fn decoding_key_falls_back_to_x5c_when_components_are_absent() {
+        let jwk = x5c_only_rsa_jwk();
+
+        let token = encode(
+            &Header { kid: Some("x5c-rsa".to_owned()), ..Header::new(Algorithm::RS256) },
+            &Claims { sub: "b@b.com".to_owned(), exp: 2_000_000_000 },
+            &EncodingKey::from_rsa_pem(RSA_TEST_KEY_PEM).unwrap(),
+        )
+        .unwrap();
+
+        let decoding_key = jwk.decoding_key().unwrap();
+        let data = decode::<Claims>(&token, &decoding_key, &Validation::new(Algorithm::RS256)).unwrap();
+        assert_eq!(data.claims.sub, "b@b.com");
+    }
+
+    #[test]
+    fn decoding_key_errors_when_neither_components_nor_x5c_are_present() {
+        let jwk = Jwk {
+            kty: "RSA".to_owned(),
+            kid: Some("bare".to_owned()),
+            alg: Some("RS256".to_owned()),
+            use_: Some("sig".to_owned()),
+            n: None,
+            e: None,
+            crv: None,
+            x: None,
+            y: None,
+            x5c: None,
+        };
+
+        assert!(jwk.decoding_key().is_err());
+    }
+}
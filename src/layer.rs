@@ -0,0 +1,86 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use axum::{extract::Request, http::StatusCode, middleware::Next, response::Response};
+
+use crate::jwks::JwkCache;
+use crate::oidc::OidcValidator;
+use crate::spiffe::SpiffeValidator;
+use crate::validation::Validation;
+
+type ValidateFuture = Pin<Box<dyn Future<Output = bool> + Send>>;
+
+/// Tower/Axum middleware layer that rejects requests carrying an invalid or missing
+/// bearer token.
+///
+/// Built from one of the `JwtAuthorizer::from_*` constructors; regardless of which
+/// validation mode it was built with, it drops into an Axum router the same way.
+#[derive(Clone)]
+pub struct AuthorizationLayer {
+    validator: Arc<dyn Fn(String) -> ValidateFuture + Send + Sync>,
+}
+
+impl AuthorizationLayer {
+    /// Builds an [`AuthorizationLayer`] that validates JWTs against an OIDC provider's
+    /// JWKS, fetched from `jwks_uri` and kept fresh in the background.
+    pub fn from_oidc(jwks_uri: impl Into<String>, validation: Validation) -> Self {
+        let keys = JwkCache::with_default_ttl(jwks_uri);
+        keys.spawn_background_refresh();
+        let validator = Arc::new(OidcValidator::new(keys, validation));
+
+        Self {
+            validator: Arc::new(move |token| {
+                let validator = Arc::clone(&validator);
+                Box::pin(async move { validator.validate(&token).await.is_ok() })
+            }),
+        }
+    }
+
+    /// Builds an [`AuthorizationLayer`] that validates SPIFFE JWT-SVIDs against `validator`.
+    pub fn from_spiffe(validator: SpiffeValidator) -> Self {
+        let validator = Arc::new(validator);
+        Self {
+            validator: Arc::new(move |token| {
+                let validator = Arc::clone(&validator);
+                Box::pin(async move { validator.validate(&token).is_ok() })
+            }),
+        }
+    }
+
+    /// Axum middleware entry point: rejects the request with `401 Unauthorized` unless
+    /// the `Authorization: Bearer <token>` header carries a token this layer accepts.
+    pub async fn authorize(&self, request: Request, next: Next) -> Result<Response, StatusCode> {
+        let token = request
+            .headers()
+            .get(axum::http::header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::to_owned)
+            .ok_or(StatusCode::UNAUTHORIZED)?;
+
+        if (self.validator)(token).await {
+            Ok(next.run(request).await)
+        } else {
+            Err(StatusCode::UNAUTHORIZED)
+        }
+    }
+}
+
+/// Entry points for building an [`AuthorizationLayer`] under one of the supported
+/// validation modes.
+pub struct JwtAuthorizer;
+
+impl JwtAuthorizer {
+    /// Builds an authorization layer that discovers an OIDC provider's keys from its JWKS
+    /// endpoint and validates tokens against `validation`.
+    pub fn from_oidc(jwks_uri: impl Into<String>, validation: Validation) -> AuthorizationLayer {
+        AuthorizationLayer::from_oidc(jwks_uri, validation)
+    }
+
+    /// Builds an authorization layer that validates SPIFFE JWT-SVIDs against keys from a
+    /// trust-domain bundle, instead of discovering an issuer via OIDC.
+    pub fn from_spiffe(validator: SpiffeValidator) -> AuthorizationLayer {
+        AuthorizationLayer::from_spiffe(validator)
+    }
+}
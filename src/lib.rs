@@ -0,0 +1,16 @@
+//! `jwt-authorizer`: a Tower/Axum layer that validates JWTs against an OIDC-discovered
+//! or statically configured JSON Web Key Set before letting requests through.
+
+mod error;
+mod jwks;
+mod layer;
+mod oidc;
+mod spiffe;
+mod validation;
+
+pub use error::AuthError;
+pub use jwks::{Jwk, JwkCache};
+pub use layer::{AuthorizationLayer, JwtAuthorizer};
+pub use oidc::{Claims, OidcValidator};
+pub use spiffe::{SpiffeValidator, SvidClaims, TrustDomainBundle};
+pub use validation::Validation;
@@ -0,0 +1,189 @@
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use jsonwebtoken::{decode, decode_header, TokenData};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::error::AuthError;
+use crate::jwks::JwkCache;
+use crate::validation::Validation;
+
+/// Claims validated by the OIDC authorization path.
+///
+/// `aud` accepts either a single string or an array per RFC 7519.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claims {
+    pub iss: String,
+    pub sub: String,
+    pub exp: usize,
+    #[serde(default)]
+    pub nbf: Option<usize>,
+    #[serde(default)]
+    pub iat: Option<usize>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub aud: Option<Vec<String>>,
+}
+
+fn one_or_many<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<OneOrMany>::deserialize(deserializer)? {
+        None => None,
+        Some(OneOrMany::One(aud)) => Some(vec![aud]),
+        Some(OneOrMany::Many(aud)) => Some(aud),
+    })
+}
+
+/// Validates JWTs issued by an OIDC provider against its JWKS, as kept fresh by a
+/// [`JwkCache`].
+pub struct OidcValidator {
+    keys: Arc<JwkCache>,
+    validation: Validation,
+}
+
+impl OidcValidator {
+    /// Creates a validator that checks tokens against `keys` using `validation`.
+    pub fn new(keys: Arc<JwkCache>, validation: Validation) -> Self {
+        Self { keys, validation }
+    }
+
+    /// Verifies `token`'s signature against the matching key in the JWKS, then checks its
+    /// claims (algorithm, audience, `exp`/`nbf`/`iat` with configured leeway).
+    pub async fn validate(&self, token: &str) -> Result<TokenData<Claims>, AuthError> {
+        let header = decode_header(token)?;
+        let kid = header.kid.ok_or(AuthError::NoMatchingKey(None))?;
+
+        let jwk = self
+            .keys
+            .get(&kid)
+            .await
+            .ok_or_else(|| AuthError::NoMatchingKey(Some(kid.clone())))?;
+
+        let decoding_key = jwk.decoding_key()?;
+        let validation = self.validation.to_jsonwebtoken_validation(jwk.compatible_algorithms());
+        let data: TokenData<Claims> = decode(token, &decoding_key, &validation)?;
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("system clock before epoch").as_secs() as usize;
+        self.validation
+            .check_claims_time(now, Some(data.claims.exp), data.claims.nbf, data.claims.iat)?;
+
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonwebtoken::{encode, Algorithm, DecodingKey, EncodingKey, Header};
+
+    use super::*;
+    use crate::jwks::Jwk;
+
+    const RSA_TEST_KEY_PEM: &[u8] = include_bytes!("../testdata/rsa_test_key.pem");
+    const RSA_TEST_N: &str = "5jDsaDOTFNxXi0ujTxagBfFL0BqoN1DHv0lMqAt6Dyote2fk6zNXiGIVDH3mDukCXLvAl_-9ME3Ukl967dPx3UWPAojPTtBdxTNwO1odh1HtR370yJv1ag4eO02mSWRaoMFx7i4c5pB2xfzOlil01nLW1nkNn8TJejSlnjbqyr3VWfW6kq8houEV6wKA--2JvSC85OubMGzBy5lPYVhsg81gPFvxY1setK66Cwt_OoSJW_2ehet7_m8eUaoLLFuqiLggspMrF1MH96qj3TSgT102EBw6MCGwAZDtHiqLOVB9_SDZkvKGAK63TW07Z8yC1Dokem80L5IEY2oOoUy-Bw";
+    const RSA_TEST_E: &str = "AQAB";
+
+    fn rsa_jwk(kid: &str) -> Jwk {
+        Jwk {
+            kty: "RSA".to_owned(),
+            kid: Some(kid.to_owned()),
+            alg: Some("PS256".to_owned()),
+            use_: Some("sig".to_owned()),
+            n: Some(RSA_TEST_N.to_owned()),
+            e: Some(RSA_TEST_E.to_owned()),
+            crv: None,
+            x: None,
+            y: None,
+            x5c: None,
+        }
+    }
+
+    fn claims(aud: Option<Vec<&str>>) -> Claims {
+        Claims {
+            iss: "https://issuer.example".to_owned(),
+            sub: "b@b.com".to_owned(),
+            exp: 2_000_000_000,
+            nbf: None,
+            iat: None,
+            aud: aud.map(|aud| aud.into_iter().map(str::to_owned).collect()),
+        }
+    }
+
+    #[test]
+    fn ps256_token_verifies_against_n_e_decoded_rsa_jwk() {
+        let token = encode(
+            &Header { kid: Some("rsa1".to_owned()), ..Header::new(Algorithm::PS256) },
+            &claims(None),
+            &EncodingKey::from_rsa_pem(RSA_TEST_KEY_PEM).unwrap(),
+        )
+        .unwrap();
+
+        let jwk = rsa_jwk("rsa1");
+        let decoding_key: DecodingKey = jwk.decoding_key().unwrap();
+        let validation = Validation::default().to_jsonwebtoken_validation(jwk.compatible_algorithms());
+
+        let data: TokenData<Claims> = decode(&token, &decoding_key, &validation).unwrap();
+        assert_eq!(data.claims.sub, "b@b.com");
+    }
+
+    #[test]
+    fn default_validation_accepts_a_token_that_carries_an_aud_claim() {
+        // `jsonwebtoken::Validation::default()` has `validate_aud = true` with no audience
+        // configured, which rejects *any* `aud`-bearing token outright unless we explicitly
+        // disable it when `Validation::audiences` is unset.
+        let token = encode(
+            &Header { kid: Some("rsa1".to_owned()), ..Header::new(Algorithm::RS256) },
+            &claims(Some(vec!["aud1"])),
+            &EncodingKey::from_rsa_pem(RSA_TEST_KEY_PEM).unwrap(),
+        )
+        .unwrap();
+
+        let jwk = rsa_jwk("rsa1");
+        let decoding_key: DecodingKey = jwk.decoding_key().unwrap();
+        let validation = Validation::default().to_jsonwebtoken_validation(jwk.compatible_algorithms());
+
+        let data: TokenData<Claims> = decode(&token, &decoding_key, &validation).unwrap();
+        assert_eq!(data.claims.aud, Some(vec!["aud1".to_owned()]));
+    }
+
+    #[test]
+    fn any_of_these_audience_accepts_multi_valued_aud() {
+        let token = encode(
+            &Header { kid: Some("rsa1".to_owned()), ..Header::new(Algorithm::RS256) },
+            &claims(Some(vec!["aud1", "aud2"])),
+            &EncodingKey::from_rsa_pem(RSA_TEST_KEY_PEM).unwrap(),
+        )
+        .unwrap();
+
+        let jwk = rsa_jwk("rsa1");
+        let decoding_key: DecodingKey = jwk.decoding_key().unwrap();
+        let validation = Validation::default().with_audiences(["aud2"]).to_jsonwebtoken_validation(jwk.compatible_algorithms());
+
+        let data: TokenData<Claims> = decode(&token, &decoding_key, &validation).unwrap();
+        assert_eq!(data.claims.aud, Some(vec!["aud1".to_owned(), "aud2".to_owned()]));
+    }
+
+    #[test]
+    fn any_of_these_audience_rejects_disjoint_aud() {
+        let token = encode(
+            &Header { kid: Some("rsa1".to_owned()), ..Header::new(Algorithm::RS256) },
+            &claims(Some(vec!["aud1", "aud2"])),
+            &EncodingKey::from_rsa_pem(RSA_TEST_KEY_PEM).unwrap(),
+        )
+        .unwrap();
+
+        let jwk = rsa_jwk("rsa1");
+        let decoding_key: DecodingKey = jwk.decoding_key().unwrap();
+        let validation = Validation::default().with_audiences(["aud3"]).to_jsonwebtoken_validation(jwk.compatible_algorithms());
+
+        let result: Result<TokenData<Claims>, _> = decode(&token, &decoding_key, &validation);
+        assert!(result.is_err());
+    }
+}
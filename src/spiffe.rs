@@ -0,0 +1,188 @@
+use std::collections::{HashMap, HashSet};
+
+use jsonwebtoken::{decode, decode_header, Algorithm, TokenData, Validation as JwtValidation};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AuthError;
+use crate::jwks::Jwk;
+
+/// Claims carried by a SPIFFE JWT-SVID, per the SPIFFE JWT-SVID specification.
+///
+/// `sub` is the workload's SPIFFE ID; `aud` is the audience the SVID was minted for.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SvidClaims {
+    pub sub: String,
+    pub iss: String,
+    pub aud: Vec<String>,
+    pub exp: usize,
+}
+
+/// A trust-domain bundle: the JWK set a SPIFFE trust domain publishes for verifying the
+/// JWT-SVIDs it issues. [`SpiffeValidator::load_bundle`] indexes its keys by `kid`.
+#[derive(Debug, Default, Deserialize)]
+pub struct TrustDomainBundle {
+    keys: Vec<Jwk>,
+}
+
+/// Validates SPIFFE JWT-SVIDs against one or more trust-domain bundles.
+///
+/// Unlike the OIDC-discovery flow, there is no issuer metadata document to fetch: the
+/// trust domain is derived from the token's `sub` claim and its keys come from a bundle
+/// the caller loads out of band (e.g. a SPIFFE Federation endpoint or a local file).
+pub struct SpiffeValidator {
+    trust_domain: String,
+    audience: String,
+    keys_by_kid: HashMap<(String, String), Jwk>,
+}
+
+impl SpiffeValidator {
+    /// Creates a validator that only accepts JWT-SVIDs issued by `trust_domain` for
+    /// `audience`.
+    pub fn new(trust_domain: impl Into<String>, audience: impl Into<String>) -> Self {
+        Self {
+            trust_domain: trust_domain.into(),
+            audience: audience.into(),
+            keys_by_kid: HashMap::new(),
+        }
+    }
+
+    /// Loads (or refreshes) the keys for `trust_domain` from a freshly fetched bundle.
+    pub fn load_bundle(&mut self, trust_domain: &str, bundle: TrustDomainBundle) {
+        self.keys_by_kid.retain(|(td, _), _| td != trust_domain);
+        for key in bundle.keys {
+            if let Some(kid) = key.kid.clone() {
+                self.keys_by_kid.insert((trust_domain.to_owned(), kid), key);
+            }
+        }
+    }
+
+    /// Validates a JWT-SVID, checking its signature against the bundle, the SPIFFE ID
+    /// shape of `sub`, that `sub`'s trust domain matches both the issuer and the
+    /// configured expected trust domain, and that the configured audience is present.
+    pub fn validate(&self, token: &str) -> Result<TokenData<SvidClaims>, AuthError> {
+        let header = decode_header(token)?;
+        let kid = header.kid.clone().ok_or(AuthError::NoMatchingKey(None))?;
+
+        let jwk = self
+            .keys_by_kid
+            .get(&(self.trust_domain.clone(), kid.clone()))
+            .ok_or_else(|| AuthError::NoMatchingKey(Some(kid.clone())))?;
+
+        let decoding_key = jwk.decoding_key()?;
+
+        let mut validation = JwtValidation::new(Algorithm::ES256);
+        validation.set_audience(std::slice::from_ref(&self.audience));
+        validation.validate_exp = true;
+
+        let data: TokenData<SvidClaims> = decode(token, &decoding_key, &validation)?;
+
+        let spiffe_trust_domain = trust_domain_of(&data.claims.sub)
+            .ok_or_else(|| AuthError::UnsupportedKeyType(format!("not a SPIFFE ID: {}", data.claims.sub)))?;
+
+        if spiffe_trust_domain != self.trust_domain {
+            return Err(AuthError::UnsupportedKeyType(format!(
+                "SPIFFE ID trust domain {spiffe_trust_domain} does not match expected {}",
+                self.trust_domain
+            )));
+        }
+
+        let issuer_trust_domain = trust_domain_of(&data.claims.iss)
+            .ok_or_else(|| AuthError::UnsupportedKeyType(format!("not a SPIFFE ID: {}", data.claims.iss)))?;
+
+        if issuer_trust_domain != spiffe_trust_domain {
+            return Err(AuthError::UnsupportedKeyType(format!(
+                "issuer trust domain {issuer_trust_domain} does not match sub trust domain {spiffe_trust_domain}"
+            )));
+        }
+
+        let audiences: HashSet<&str> = data.claims.aud.iter().map(String::as_str).collect();
+        if !audiences.contains(self.audience.as_str()) {
+            return Err(AuthError::UnsupportedKeyType("audience mismatch".to_owned()));
+        }
+
+        Ok(data)
+    }
+}
+
+/// Extracts the trust domain out of a `spiffe://<trust-domain>/<path>` URI, if `sub`
+/// is a syntactically valid SPIFFE ID.
+fn trust_domain_of(sub: &str) -> Option<&str> {
+    let rest = sub.strip_prefix("spiffe://")?;
+    let (trust_domain, path) = match rest.split_once('/') {
+        Some((trust_domain, path)) => (trust_domain, Some(path)),
+        None => (rest, None),
+    };
+    let trust_domain_ok = !trust_domain.is_empty()
+        && trust_domain.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '-' | '_'));
+    let path_ok = path.map_or(true, |path| {
+        path.split('/').all(|segment| {
+            !segment.is_empty() && segment != "." && segment != ".." && segment.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+        })
+    });
+    if trust_domain_ok && path_ok {
+        Some(trust_domain)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+
+    use super::*;
+
+    const EC_TEST_KEY_PEM: &[u8] = include_bytes!("../testdata/ec_test_key.pem");
+    const EC_TEST_X: &str = "kUg-KBuxThyw4QbYO4dQl9d5ulm_YyjJRDwEamBwx3A";
+    const EC_TEST_Y: &str = "VEP1EINK7St5ZLMUC0I8aG_v6Z63ocueqYv88zMzwz4";
+
+    fn bundle_with_ec_key(kid: &str) -> TrustDomainBundle {
+        TrustDomainBundle {
+            keys: vec![Jwk {
+                kty: "EC".to_owned(),
+                kid: Some(kid.to_owned()),
+                alg: Some("ES256".to_owned()),
+                use_: Some("sig".to_owned()),
+                n: None,
+                e: None,
+                crv: Some("P-256".to_owned()),
+                x: Some(EC_TEST_X.to_owned()),
+                y: Some(EC_TEST_Y.to_owned()),
+                x5c: None,
+            }],
+        }
+    }
+
+    fn svid_token(sub: &str, iss: &str, aud: &str, kid: &str) -> String {
+        let claims = SvidClaims { sub: sub.to_owned(), iss: iss.to_owned(), aud: vec![aud.to_owned()], exp: 2_000_000_000 };
+        encode(
+            &Header { kid: Some(kid.to_owned()), ..Header::new(Algorithm::ES256) },
+            &claims,
+            &EncodingKey::from_ec_pem(EC_TEST_KEY_PEM).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn accepts_svid_whose_issuer_matches_sub_trust_domain() {
+        let mut validator = SpiffeValidator::new("td1.example", "api");
+        validator.load_bundle("td1.example", bundle_with_ec_key("ec1"));
+
+        let token = svid_token("spiffe://td1.example/workload", "spiffe://td1.example/ca", "api", "ec1");
+
+        assert!(validator.validate(&token).is_ok());
+    }
+
+    #[test]
+    fn rejects_svid_whose_issuer_trust_domain_does_not_match_sub() {
+        let mut validator = SpiffeValidator::new("td1.example", "api");
+        validator.load_bundle("td1.example", bundle_with_ec_key("ec1"));
+
+        // `iss` claims a different trust domain than `sub` — an attacker-controlled or
+        // misconfigured issuer shouldn't be able to vouch for another trust domain's
+        // workload identity.
+        let token = svid_token("spiffe://td1.example/workload", "spiffe://other.example/ca", "api", "ec1");
+
+        assert!(validator.validate(&token).is_err());
+    }
+}
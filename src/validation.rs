@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use jsonwebtoken::Algorithm;
+
+use crate::error::AuthError;
+
+/// The signature algorithms the authorizer will accept out of the box.
+///
+/// RSA-PSS (`PS256`/`PS384`/`PS512`) verifies against the same RSA public key material as
+/// `RS256`/`RS384`/`RS512`, so providers that default to RSA-PSS need no extra key handling.
+/// Limited to the families [`crate::jwks::Jwk::decoding_key`] can actually build a
+/// `DecodingKey` for (RSA and P-256 EC) — `ES384` and `EdDSA` are left out because there is
+/// no OKP/P-384 decoding path yet, and advertising an algorithm the key layer can't build a
+/// key for would only ever fail with `UnsupportedKeyType`.
+const DEFAULT_ALGORITHMS: &[Algorithm] = &[
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+];
+
+/// Validation settings applied to every incoming JWT.
+#[derive(Debug, Clone)]
+pub struct Validation {
+    pub(crate) algorithms: Vec<Algorithm>,
+    pub(crate) leeway: Duration,
+    pub(crate) audiences: Option<HashSet<String>>,
+}
+
+impl Validation {
+    /// Creates a validation configuration that only accepts the given algorithms.
+    pub fn new(algorithms: impl IntoIterator<Item = Algorithm>) -> Self {
+        Self {
+            algorithms: algorithms.into_iter().collect(),
+            leeway: Duration::ZERO,
+            audiences: None,
+        }
+    }
+
+    /// Sets the set of acceptable audiences.
+    ///
+    /// A token is accepted if *any* of its `aud` values (the claim may be a single string
+    /// or an array per RFC 7519) appears in `audiences` — set-intersection, not an exact
+    /// match against a single configured audience.
+    pub fn with_audiences(mut self, audiences: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.audiences = Some(audiences.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the clock-skew tolerance applied to `exp`, `nbf` and `iat`.
+    ///
+    /// Issuer and verifier clocks commonly drift by a few seconds, so without a leeway a
+    /// token can be rejected as expired or not-yet-valid even though it is legitimate.
+    /// With `leeway`, a token is accepted if `now <= exp + leeway` and
+    /// `now >= nbf - leeway`, applied symmetrically to `iat` as well.
+    pub fn with_leeway(mut self, leeway: Duration) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    /// Builds the underlying `jsonwebtoken` validation, narrowed to the algorithms in
+    /// `compatible_algorithms` — `jsonwebtoken` errors with `InvalidAlgorithm` if a
+    /// `Validation` lists even one algorithm from a different key family than the
+    /// `DecodingKey` it's checked against, so a multi-family [`Validation`] (the default)
+    /// can't be handed to `decode` as-is once a specific key has been matched.
+    pub(crate) fn to_jsonwebtoken_validation(&self, compatible_algorithms: &[Algorithm]) -> jsonwebtoken::Validation {
+        let mut validation = jsonwebtoken::Validation::default();
+        validation.algorithms = self.algorithms.iter().filter(|alg| compatible_algorithms.contains(alg)).copied().collect();
+        // `exp`/`nbf` are checked by `check_claims_time` instead, alongside `iat` (which
+        // `jsonwebtoken` has no concept of at all), so the configured leeway applies
+        // symmetrically to all three from a single place.
+        validation.validate_exp = false;
+        validation.validate_nbf = false;
+        if let Some(audiences) = &self.audiences {
+            // `jsonwebtoken` already matches an array-valued `aud` against this set with
+            // any-of-these semantics, so no extra intersection logic is needed here.
+            validation.set_audience(&audiences.iter().collect::<Vec<_>>());
+        } else {
+            // `jsonwebtoken::Validation::default()` leaves `validate_aud = true` with no
+            // configured audience, which rejects every token that carries an `aud` claim
+            // at all. With no audiences configured, any `aud` is acceptable.
+            validation.validate_aud = false;
+        }
+        validation
+    }
+
+    /// Checks `exp`, `nbf` and `iat` against `now`, tolerating [`Validation::leeway`] of
+    /// clock skew in either direction.
+    ///
+    /// `jsonwebtoken`'s own `exp`/`nbf` validation is disabled in
+    /// [`Validation::to_jsonwebtoken_validation`] in favor of this method, so that the
+    /// configured leeway is applied the same way to `exp`, `nbf` and `iat` instead of only
+    /// the first two.
+    pub(crate) fn check_claims_time(&self, now: usize, exp: Option<usize>, nbf: Option<usize>, iat: Option<usize>) -> Result<(), AuthError> {
+        let leeway = self.leeway.as_secs() as usize;
+
+        if let Some(exp) = exp {
+            if now > exp.saturating_add(leeway) {
+                return Err(AuthError::Expired);
+            }
+        }
+        if let Some(nbf) = nbf {
+            if now < nbf.saturating_sub(leeway) {
+                return Err(AuthError::NotYetValid);
+            }
+        }
+        if let Some(iat) = iat {
+            if now < iat.saturating_sub(leeway) {
+                return Err(AuthError::IssuedInTheFuture);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for Validation {
+    /// Accepts every signature family this crate knows how to verify against an RSA or
+    /// EC JWK, including RSA-PSS, and applies no clock-skew leeway.
+    fn default() -> Self {
+        Self::new(DEFAULT_ALGORITHMS.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leeway_tolerates_clock_skew_on_nbf_and_iat() {
+        let now = 1_000;
+        let validation = Validation::default().with_leeway(Duration::from_secs(30));
+
+        // `nbf`/`iat` a few seconds ahead of `now` would fail a strict comparison, but
+        // should be tolerated within the configured leeway.
+        assert!(validation.check_claims_time(now, Some(2_000), Some(now + 10), Some(now + 10)).is_ok());
+    }
+
+    #[test]
+    fn leeway_zero_rejects_clock_skew() {
+        let now = 1_000;
+        let validation = Validation::default();
+
+        assert!(matches!(
+            validation.check_claims_time(now, Some(2_000), Some(now + 10), None),
+            Err(AuthError::NotYetValid)
+        ));
+    }
+
+    #[test]
+    fn with_audiences_sets_the_jsonwebtoken_audience_set() {
+        let validation = Validation::default().with_audiences(["aud2"]).to_jsonwebtoken_validation(DEFAULT_ALGORITHMS);
+
+        let aud = validation.aud.expect("audience set");
+        assert!(aud.contains("aud2"));
+        assert!(!aud.contains("aud1"));
+    }
+}